@@ -0,0 +1,155 @@
+// Descriptor wallet library extending bitcoin & miniscript functionality
+// by LNP/BP Association (https://lnp-bp.org)
+// Written in 2020-2022 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the Apache-2.0 License
+// along with this software.
+// If not, see <https://opensource.org/licenses/Apache-2.0>.
+
+//! Gap-limit policy for account & address discovery, per BIP-44.
+//!
+//! Pair this with [`crate::schemata::DerivationStandard::key_derivations`] or
+//! [`crate::schemata::DerivationStandard::account_derivations`] to scan a
+//! blockchain backend: keep asking whether each successive derivation path
+//! is used, and stop once [`GapLimit::scan`] has seen enough consecutive
+//! unused ones in a row.
+
+use bitcoin::util::bip32::DerivationPath;
+
+/// Default BIP-44 gap limit: the number of consecutive unused indexes after
+/// which a wallet should stop scanning further.
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// Policy controlling how many consecutive unused derivation paths a
+/// discovery scan tolerates before giving up on finding more funds.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct GapLimit(u32);
+
+impl Default for GapLimit {
+    fn default() -> Self { Self(DEFAULT_GAP_LIMIT) }
+}
+
+impl GapLimit {
+    /// Constructs a gap limit policy with a custom window size.
+    pub fn new(limit: u32) -> Self { Self(limit) }
+
+    /// Returns the configured gap limit window.
+    pub fn limit(self) -> u32 { self.0 }
+
+    /// Scans `derivations` in order, querying `is_used` for each, and
+    /// returns every used path found. Scanning stops once `self.limit()`
+    /// consecutive unused paths have been seen, or `derivations` is
+    /// exhausted.
+    pub fn scan<E>(
+        self,
+        derivations: impl Iterator<Item = DerivationPath>,
+        mut is_used: impl FnMut(&DerivationPath) -> Result<bool, E>,
+    ) -> Result<Vec<DerivationPath>, E> {
+        let mut found = Vec::new();
+        let mut gap = 0u32;
+        for derivation in derivations {
+            if is_used(&derivation)? {
+                gap = 0;
+                found.push(derivation);
+            } else {
+                gap += 1;
+                if gap >= self.limit() {
+                    break;
+                }
+            }
+        }
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use super::*;
+    use crate::schemata::{Bip43, DerivationBlockchain, DerivationStandard};
+    use crate::HardenedIndex;
+
+    fn path(account: u32, index: u32) -> DerivationPath {
+        Bip43::Bip84.to_key_derivation(
+            HardenedIndex(account).into(),
+            DerivationBlockchain::Bitcoin,
+            crate::UnhardenedIndex::try_from(index).unwrap(),
+            None,
+        )
+    }
+
+    #[test]
+    fn key_derivations_covers_requested_range() {
+        let scheme = Bip43::Bip84;
+        let paths: Vec<_> = scheme
+            .key_derivations(
+                HardenedIndex(0).into(),
+                DerivationBlockchain::Bitcoin,
+                None,
+                0..3,
+            )
+            .collect();
+        assert_eq!(paths, vec![path(0, 0), path(0, 1), path(0, 2)]);
+    }
+
+    #[test]
+    fn account_derivations_covers_requested_range() {
+        let scheme = Bip43::Bip84;
+        let paths: Vec<_> = scheme
+            .account_derivations(DerivationBlockchain::Bitcoin, 0..2)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                scheme.to_account_derivation(HardenedIndex(0).into(), DerivationBlockchain::Bitcoin),
+                scheme.to_account_derivation(HardenedIndex(1).into(), DerivationBlockchain::Bitcoin),
+            ]
+        );
+    }
+
+    #[test]
+    fn default_gap_limit_is_twenty() {
+        assert_eq!(GapLimit::default().limit(), DEFAULT_GAP_LIMIT);
+    }
+
+    #[test]
+    fn scan_stops_after_consecutive_unused() {
+        let scheme = Bip43::Bip84;
+        let derivations = scheme.key_derivations(
+            HardenedIndex(0).into(),
+            DerivationBlockchain::Bitcoin,
+            None,
+            0..100,
+        );
+
+        // Only index 1 is used; with a gap limit of 2 the scan should stop
+        // right after seeing indexes 2 and 3 unused, never reaching index 4.
+        let used = path(0, 1);
+        let mut queried = 0u32;
+        let result: Result<_, core::convert::Infallible> =
+            GapLimit::new(2).scan(derivations, |derivation| {
+                queried += 1;
+                Ok(*derivation == used)
+            });
+        let found = result.unwrap();
+
+        assert_eq!(found, vec![used]);
+        assert_eq!(queried, 4);
+    }
+
+    #[test]
+    fn scan_propagates_backend_errors() {
+        let scheme = Bip43::Bip84;
+        let derivations =
+            scheme.key_derivations(HardenedIndex(0).into(), DerivationBlockchain::Bitcoin, None, 0..5);
+        let result = GapLimit::default().scan(derivations, |_| Err("backend offline"));
+        assert_eq!(result, Err("backend offline"));
+    }
+}
@@ -15,6 +15,8 @@
 //! Derivation schemata based on BIP-43-related standards.
 
 use core::convert::TryInto;
+use core::fmt::Display;
+use core::ops::Range;
 use core::str::FromStr;
 use std::convert::TryFrom;
 
@@ -76,21 +78,93 @@ pub enum DerivationBlockchain {
     #[display("testnet")]
     Testnet,
 
-    /// Custom blockchain (non-testnet)
+    /// Litecoin, SLIP-44 coin type 2'
+    #[display("litecoin")]
+    Litecoin,
+
+    /// Dogecoin, SLIP-44 coin type 3'
+    #[display("dogecoin")]
+    Dogecoin,
+
+    /// Dash, SLIP-44 coin type 5'
+    #[display("dash")]
+    Dash,
+
+    /// Ethereum, SLIP-44 coin type 60'
+    #[display("ethereum")]
+    Ethereum,
+
+    /// Ethereum Classic, SLIP-44 coin type 61'
+    #[display("ethereum-classic")]
+    EthereumClassic,
+
+    /// Monero, SLIP-44 coin type 128'
+    #[display("monero")]
+    Monero,
+
+    /// Zcash, SLIP-44 coin type 133'
+    #[display("zcash")]
+    Zcash,
+
+    /// Bitcoin Cash, SLIP-44 coin type 145'
+    #[display("bitcoin-cash")]
+    BitcoinCash,
+
+    /// Custom blockchain identified by its raw SLIP-44 coin type
     #[display(inner)]
     #[from]
     Custom(HardenedIndex),
 }
 
 impl DerivationBlockchain {
+    /// Constructs a blockchain variant from its SLIP-44 coin type, falling
+    /// back to [`DerivationBlockchain::Custom`] for unregistered coin types.
+    ///
+    /// Returns `None` if `coin_type` does not fit into a hardened index
+    /// (i.e. is `0x8000_0000` or greater), since such a coin type cannot be
+    /// represented as a derivation path segment.
+    pub fn from_coin_type(coin_type: u32) -> Option<Self> {
+        Some(match coin_type {
+            0 => Self::Bitcoin,
+            1 => Self::Testnet,
+            2 => Self::Litecoin,
+            3 => Self::Dogecoin,
+            5 => Self::Dash,
+            60 => Self::Ethereum,
+            61 => Self::EthereumClassic,
+            128 => Self::Monero,
+            133 => Self::Zcash,
+            145 => Self::BitcoinCash,
+            other => Self::Custom(HardenedIndex::try_from(other).ok()?),
+        })
+    }
+
+    /// Returns the SLIP-44 coin type registered for this blockchain.
+    pub fn coin_type(self) -> u32 {
+        match self {
+            Self::Bitcoin => 0,
+            Self::Testnet => 1,
+            Self::Litecoin => 2,
+            Self::Dogecoin => 3,
+            Self::Dash => 5,
+            Self::Ethereum => 60,
+            Self::EthereumClassic => 61,
+            Self::Monero => 128,
+            Self::Zcash => 133,
+            Self::BitcoinCash => 145,
+            Self::Custom(index) => index.0,
+        }
+    }
+
     /// Returns derivation path segment child number corresponding to the given
     /// blockchain from LNPBP-43 standard
     #[inline]
     pub fn child_number(self) -> ChildNumber {
         match self {
-            Self::Bitcoin => ChildNumber::Hardened { index: 0 },
-            Self::Testnet => ChildNumber::Hardened { index: 1 },
             Self::Custom(index) => index.into(),
+            other => HardenedIndex::try_from(other.coin_type())
+                .expect("SLIP-44 coin type must fit into a hardened index")
+                .into(),
         }
     }
 }
@@ -103,6 +177,14 @@ impl FromStr for DerivationBlockchain {
         match (s.to_lowercase().as_str(), parsed) {
             ("bitcoin", _) => Ok(Self::Bitcoin),
             ("testnet", _) => Ok(Self::Testnet),
+            ("litecoin", _) => Ok(Self::Litecoin),
+            ("dogecoin", _) => Ok(Self::Dogecoin),
+            ("dash", _) => Ok(Self::Dash),
+            ("ethereum", _) => Ok(Self::Ethereum),
+            ("ethereum-classic", _) => Ok(Self::EthereumClassic),
+            ("monero", _) => Ok(Self::Monero),
+            ("zcash", _) => Ok(Self::Zcash),
+            ("bitcoin-cash", _) => Ok(Self::BitcoinCash),
             (_, Ok(index @ ChildNumber::Hardened { .. })) => {
                 Ok(Self::Custom(index.try_into().expect(
                     "ChildNumber::Hardened failed to convert into HardenedIndex type",
@@ -116,6 +198,19 @@ impl FromStr for DerivationBlockchain {
     }
 }
 
+impl TryFrom<ChildNumber> for DerivationBlockchain {
+    type Error = ParseError;
+
+    fn try_from(child_number: ChildNumber) -> Result<Self, Self::Error> {
+        match child_number {
+            ChildNumber::Hardened { index } => Ok(Self::from_coin_type(index).expect(
+                "ChildNumber::Hardened index always fits into a hardened index",
+            )),
+            ChildNumber::Normal { index } => Err(ParseError::UnhardenedBlockchainIndex(index)),
+        }
+    }
+}
+
 /// Specific derivation scheme after BIP-43 standards
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Display)]
 #[cfg_attr(feature = "clap", derive(ArgEnum))]
@@ -222,6 +317,75 @@ impl FromStr for Bip43 {
     }
 }
 
+/// Structured components of a derivation path recognized under one of the
+/// BIP-43-related standards, as produced by
+/// [`DerivationStandard::decompose`].
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct DerivationComponents {
+    /// Blockchain the path was derived for.
+    pub blockchain: DerivationBlockchain,
+
+    /// Account index (hardened).
+    pub account: HardenedIndex,
+
+    /// BIP-48 script type segment, present only for [`Bip43::Bip48Nested`]
+    /// and [`Bip43::Bip48Native`].
+    pub script_type: Option<HardenedIndex>,
+
+    /// Address case (receive/change), if present in the path.
+    pub case: Option<UnhardenedIndex>,
+
+    /// Address index, if present in the path.
+    pub index: Option<UnhardenedIndex>,
+}
+
+/// Elliptic curve used by a derivation standard, determining whether
+/// unhardened (public/neutered) child derivation is available.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Display)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum Curve {
+    /// secp256k1, as used by Bitcoin and BIP-32; supports both hardened and
+    /// unhardened derivation.
+    #[display("secp256k1")]
+    Secp256k1,
+
+    /// ed25519, as used by SLIP-0010-derived wallets (e.g. Solana, Duniter);
+    /// only hardened derivation is defined.
+    #[display("ed25519")]
+    Ed25519,
+}
+
+impl Curve {
+    /// Returns `true` if this curve permits unhardened (public) child
+    /// derivation.
+    #[inline]
+    pub fn allows_unhardened_derivation(self) -> bool { self == Curve::Secp256k1 }
+}
+
+/// Error returned by [`DerivationStandard::to_checked_key_derivation`] when
+/// the requested path shape or descriptor type does not match the
+/// derivation standard used to build it.
+#[derive(Clone, Eq, PartialEq, Debug, Error, Display)]
+#[display(doc_comments)]
+pub enum DerivationMismatch {
+    /// descriptor type {0:?} is not compatible with derivation scheme
+    /// `{1}`
+    DescriptorType(DescriptorType, String),
+
+    /// account index {0:?} must be hardened to be used with derivation
+    /// scheme `{1}`
+    UnhardenedAccountIndex(ChildNumber, String),
+}
+
 /// Methods for derivation standard enumeration types.
 pub trait DerivationStandard {
     /// Reconstructs derivation scheme used by the provided derivation path, if
@@ -230,9 +394,44 @@ pub trait DerivationStandard {
     where
         Self: Sized;
 
+    /// Elliptic curve this derivation standard derives keys on.
+    fn curve(&self) -> Curve;
+
+    /// Promotes every unhardened segment of `path` to hardened if
+    /// [`Self::curve`] does not allow unhardened derivation, otherwise
+    /// returns `path` unchanged.
+    ///
+    /// Implementors of `to_origin_derivation`/`to_account_derivation`/
+    /// `to_key_derivation` for a curve that rejects unhardened derivation
+    /// (e.g. ed25519, see [`Curve::Ed25519`]) should route every path they
+    /// build through this before returning it.
+    fn enforce_curve(&self, path: DerivationPath) -> DerivationPath {
+        if self.curve().allows_unhardened_derivation() {
+            path
+        } else {
+            harden_all(path)
+        }
+    }
+
     /// Get hardened index matching BIP-43 purpose value, if any.
     fn purpose(&self) -> Option<HardenedIndex>;
 
+    /// Parses a complete derivation path against this standard, recovering
+    /// the structured [`DerivationComponents`] it was built from.
+    ///
+    /// Returns `None` if the path does not conform to this standard: a
+    /// purpose/blockchain/account/script-type segment which is not hardened,
+    /// a case/index segment which is hardened, or a path which is longer
+    /// than this standard allows, all cause rejection.
+    ///
+    /// This is the inverse of [`DerivationStandard::to_key_derivation`], i.e.
+    /// `scheme.decompose(&scheme.to_key_derivation(account, blockchain,
+    /// index, case))` reconstructs `account`, `blockchain`, `index` and
+    /// `case` unchanged, except that a [`DerivationBlockchain::Custom`]
+    /// blockchain whose coin type is registered comes back as the named
+    /// variant for that coin type.
+    fn decompose(&self, derivation: &DerivationPath) -> Option<DerivationComponents>;
+
     /// Construct derivation path for the account origin.
     fn to_origin_derivation(&self, blockchain: DerivationBlockchain) -> DerivationPath;
 
@@ -256,6 +455,81 @@ pub trait DerivationStandard {
     /// Check whether provided descriptor type can be used with this derivation
     /// scheme.
     fn check_descriptor_type(&self, descriptor_type: DescriptorType) -> bool;
+
+    /// Construct full derivation path including address index and case, but
+    /// only if `account_index` is hardened (as every BIP-43-based standard
+    /// requires) and `descriptor_type` is compatible with this derivation
+    /// standard (checked via [`DerivationStandard::check_descriptor_type`]).
+    ///
+    /// This prevents the common footgun of generating, say, a P2TR path for
+    /// a BIP-84 (native P2WPKH) descriptor, or a path with an unhardened
+    /// account segment.
+    ///
+    /// There is no separate check for the shape of the account/script-type
+    /// segments (e.g. BIP-48 requiring a script-type segment, BIP-45's
+    /// differing layout): this method has no `script_type` parameter a
+    /// caller could get wrong, since [`DerivationStandard::to_key_derivation`]
+    /// (via [`DerivationStandard::to_account_derivation`]) already hardcodes
+    /// the correct segments for `self`'s own scheme. A mismatched shape can
+    /// only happen by hand-building a [`DerivationPath`] and comparing it to
+    /// what this method returns, which is outside what this method validates.
+    fn to_checked_key_derivation(
+        &self,
+        descriptor_type: DescriptorType,
+        account_index: ChildNumber,
+        blockchain: DerivationBlockchain,
+        index: UnhardenedIndex,
+        case: Option<UnhardenedIndex>,
+    ) -> Result<DerivationPath, DerivationMismatch>
+    where
+        Self: Display,
+    {
+        if !matches!(account_index, ChildNumber::Hardened { .. }) {
+            return Err(DerivationMismatch::UnhardenedAccountIndex(
+                account_index,
+                self.to_string(),
+            ));
+        }
+        if !self.check_descriptor_type(descriptor_type) {
+            return Err(DerivationMismatch::DescriptorType(
+                descriptor_type,
+                self.to_string(),
+            ));
+        }
+        Ok(self.to_key_derivation(account_index, blockchain, index, case))
+    }
+
+    /// Iterates over key derivation paths for `account_index` and `case`,
+    /// covering address indexes `range.start..range.end`, for use when
+    /// scanning a blockchain backend for wallet recovery.
+    fn key_derivations<'s>(
+        &'s self,
+        account_index: ChildNumber,
+        blockchain: DerivationBlockchain,
+        case: Option<UnhardenedIndex>,
+        range: Range<u32>,
+    ) -> Box<dyn Iterator<Item = DerivationPath> + 's> {
+        Box::new(range.filter_map(move |index| {
+            UnhardenedIndex::try_from(index)
+                .ok()
+                .map(|index| self.to_key_derivation(account_index, blockchain, index, case))
+        }))
+    }
+
+    /// Iterates over account derivation paths covering account indexes
+    /// `range.start..range.end`, for use when scanning a blockchain backend
+    /// for accounts to recover.
+    fn account_derivations<'s>(
+        &'s self,
+        blockchain: DerivationBlockchain,
+        range: Range<u32>,
+    ) -> Box<dyn Iterator<Item = DerivationPath> + 's> {
+        Box::new(range.filter_map(move |index| {
+            HardenedIndex::try_from(index)
+                .ok()
+                .map(|index| self.to_account_derivation(index.into(), blockchain))
+        }))
+    }
 }
 
 impl DerivationStandard for Bip43 {
@@ -282,6 +556,62 @@ impl DerivationStandard for Bip43 {
         })
     }
 
+    fn curve(&self) -> Curve { Curve::Secp256k1 }
+
+    fn decompose(&self, derivation: &DerivationPath) -> Option<DerivationComponents> {
+        let mut iter = derivation.into_iter().copied();
+
+        if let Some(purpose) = self.purpose() {
+            if HardenedIndex::try_from(iter.next()?).ok()? != purpose {
+                return None;
+            }
+        }
+
+        let blockchain = DerivationBlockchain::try_from(iter.next()?).ok()?;
+        let account = HardenedIndex::try_from(iter.next()?).ok()?;
+
+        let script_type = match self {
+            Bip43::Bip48Nested => {
+                let script_type = HardenedIndex::try_from(iter.next()?).ok()?;
+                if script_type != HardenedIndex::from(1u8) {
+                    return None;
+                }
+                Some(script_type)
+            }
+            Bip43::Bip48Native => {
+                let script_type = HardenedIndex::try_from(iter.next()?).ok()?;
+                if script_type != HardenedIndex::from(2u8) {
+                    return None;
+                }
+                Some(script_type)
+            }
+            _ => None,
+        };
+
+        let index = iter
+            .next()
+            .map(UnhardenedIndex::try_from)
+            .transpose()
+            .ok()?;
+        let case = iter
+            .next()
+            .map(UnhardenedIndex::try_from)
+            .transpose()
+            .ok()?;
+
+        if iter.next().is_some() {
+            return None;
+        }
+
+        Some(DerivationComponents {
+            blockchain,
+            account,
+            script_type,
+            case,
+            index,
+        })
+    }
+
     fn purpose(&self) -> Option<HardenedIndex> {
         Some(match self {
             Bip43::Bip44 => HardenedIndex(44),
@@ -353,6 +683,95 @@ impl DerivationStandard for Bip43 {
     }
 }
 
+/// Derivation standard reusing the path shape of an underlying
+/// [`Bip43`] scheme, but on the ed25519 curve per SLIP-0010.
+///
+/// Since ed25519 does not define unhardened (public) child derivation, every
+/// segment built by this standard is hardened, regardless of whether the
+/// caller requested a hardened or unhardened index. Use
+/// [`crate::slip10`] to turn the resulting path into actual key material.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Display)]
+#[display("slip10({0})")]
+pub struct Slip10(pub Bip43);
+
+impl DerivationStandard for Slip10 {
+    fn with(derivation: &DerivationPath) -> Option<Self> { Bip43::with(derivation).map(Slip10) }
+
+    fn curve(&self) -> Curve { Curve::Ed25519 }
+
+    fn purpose(&self) -> Option<HardenedIndex> { self.0.purpose() }
+
+    fn decompose(&self, derivation: &DerivationPath) -> Option<DerivationComponents> {
+        // Every segment of a Slip10 path is hardened (see `enforce_curve`), but
+        // `Bip43::decompose` expects the trailing index/case segments to be
+        // unhardened. Un-harden just that tail before delegating, so this is
+        // the true inverse of `to_key_derivation`/`to_account_derivation`.
+        let prefix_len = self.0.purpose().is_some() as usize
+            + 1 // blockchain
+            + 1 // account
+            + matches!(self.0, Bip43::Bip48Nested | Bip43::Bip48Native) as usize;
+
+        let unhardened_tail: Vec<ChildNumber> = derivation
+            .into_iter()
+            .copied()
+            .enumerate()
+            .map(|(i, child_number)| {
+                if i < prefix_len {
+                    child_number
+                } else {
+                    match child_number {
+                        ChildNumber::Hardened { index } => ChildNumber::Normal { index },
+                        normal => normal,
+                    }
+                }
+            })
+            .collect();
+
+        self.0.decompose(&unhardened_tail.into())
+    }
+
+    fn to_origin_derivation(&self, blockchain: DerivationBlockchain) -> DerivationPath {
+        self.enforce_curve(self.0.to_origin_derivation(blockchain))
+    }
+
+    fn to_account_derivation(
+        &self,
+        account_index: ChildNumber,
+        blockchain: DerivationBlockchain,
+    ) -> DerivationPath {
+        self.enforce_curve(self.0.to_account_derivation(account_index, blockchain))
+    }
+
+    fn to_key_derivation(
+        &self,
+        account_index: ChildNumber,
+        blockchain: DerivationBlockchain,
+        index: UnhardenedIndex,
+        case: Option<UnhardenedIndex>,
+    ) -> DerivationPath {
+        self.enforce_curve(
+            self.0
+                .to_key_derivation(account_index, blockchain, index, case),
+        )
+    }
+
+    fn check_descriptor_type(&self, descriptor_type: DescriptorType) -> bool {
+        self.0.check_descriptor_type(descriptor_type)
+    }
+}
+
+/// Promotes every unhardened segment of `path` to its hardened equivalent,
+/// leaving already-hardened segments untouched.
+fn harden_all(path: DerivationPath) -> DerivationPath {
+    path.into_iter()
+        .map(|child_number| match *child_number {
+            ChildNumber::Normal { index } => ChildNumber::Hardened { index },
+            hardened => hardened,
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
 #[cfg(not(feature = "miniscript"))]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum DescriptorType {
@@ -379,3 +798,197 @@ pub enum DescriptorType {
     /// Tr Descriptor
     Tr,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompose_is_inverse_of_to_key_derivation() {
+        let scheme = Bip43::Bip84;
+        let blockchain = DerivationBlockchain::Bitcoin;
+        let account = HardenedIndex(5);
+        let index = UnhardenedIndex::try_from(3u32).unwrap();
+        let case = Some(UnhardenedIndex::try_from(0u32).unwrap());
+
+        let path = scheme.to_key_derivation(account.into(), blockchain, index, case);
+        let components = scheme.decompose(&path).expect("path must decompose");
+
+        assert_eq!(components.blockchain, blockchain);
+        assert_eq!(components.account, account);
+        assert_eq!(components.script_type, None);
+        assert_eq!(components.index, Some(index));
+        assert_eq!(components.case, case);
+    }
+
+    #[test]
+    fn decompose_is_inverse_of_to_key_derivation_for_bip48() {
+        let scheme = Bip43::Bip48Native;
+        let blockchain = DerivationBlockchain::Testnet;
+        let account = HardenedIndex(1);
+        let index = UnhardenedIndex::try_from(9u32).unwrap();
+
+        let path = scheme.to_key_derivation(account.into(), blockchain, index, None);
+        let components = scheme.decompose(&path).expect("path must decompose");
+
+        assert_eq!(components.blockchain, blockchain);
+        assert_eq!(components.account, account);
+        assert_eq!(components.script_type, Some(HardenedIndex::from(2u8)));
+        assert_eq!(components.index, Some(index));
+        assert_eq!(components.case, None);
+    }
+
+    #[test]
+    fn blockchain_coin_type_round_trips_through_str_and_child_number() {
+        for (name, coin_type) in [
+            ("bitcoin", 0),
+            ("testnet", 1),
+            ("litecoin", 2),
+            ("dogecoin", 3),
+            ("dash", 5),
+            ("ethereum", 60),
+            ("ethereum-classic", 61),
+            ("monero", 128),
+            ("zcash", 133),
+            ("bitcoin-cash", 145),
+        ] {
+            let blockchain = DerivationBlockchain::from_str(name).unwrap();
+            assert_eq!(blockchain.coin_type(), coin_type);
+            assert_eq!(blockchain.to_string(), name);
+            assert_eq!(
+                DerivationBlockchain::try_from(blockchain.child_number()).unwrap(),
+                blockchain
+            );
+        }
+    }
+
+    #[test]
+    fn blockchain_from_coin_type_falls_back_to_custom() {
+        assert_eq!(
+            DerivationBlockchain::from_coin_type(9_999),
+            Some(DerivationBlockchain::Custom(HardenedIndex(9_999)))
+        );
+    }
+
+    #[test]
+    fn blockchain_from_coin_type_rejects_out_of_range() {
+        assert_eq!(DerivationBlockchain::from_coin_type(0xFFFF_FFFF), None);
+    }
+
+    #[test]
+    fn slip10_decompose_is_inverse_of_to_key_derivation() {
+        let scheme = Slip10(Bip43::Bip44);
+        let blockchain = DerivationBlockchain::Bitcoin;
+        let account = HardenedIndex(7);
+        let index = UnhardenedIndex::try_from(2u32).unwrap();
+        let case = Some(UnhardenedIndex::try_from(1u32).unwrap());
+
+        let path = scheme.to_key_derivation(account.into(), blockchain, index, case);
+        // Every segment of a Slip10 path is hardened.
+        assert!(path
+            .into_iter()
+            .all(|cn| matches!(cn, ChildNumber::Hardened { .. })));
+
+        let components = scheme.decompose(&path).expect("slip10 path must decompose");
+        assert_eq!(components.account, account);
+        assert_eq!(components.index, Some(index));
+        assert_eq!(components.case, case);
+    }
+
+    #[test]
+    fn decompose_rejects_unhardened_account() {
+        let scheme = Bip43::Bip44;
+        let path = DerivationPath::from(vec![
+            HardenedIndex(44).into(),
+            DerivationBlockchain::Bitcoin.child_number(),
+            ChildNumber::Normal { index: 0 },
+        ]);
+        assert_eq!(scheme.decompose(&path), None);
+    }
+
+    #[test]
+    fn checked_key_derivation_accepts_matching_descriptor_type() {
+        let scheme = Bip43::Bip84;
+        let index = UnhardenedIndex::try_from(0u32).unwrap();
+        let path = scheme
+            .to_checked_key_derivation(
+                DescriptorType::Wpkh,
+                HardenedIndex(0).into(),
+                DerivationBlockchain::Bitcoin,
+                index,
+                None,
+            )
+            .expect("bip84 is compatible with Wpkh");
+        assert_eq!(
+            path,
+            scheme.to_key_derivation(HardenedIndex(0).into(), DerivationBlockchain::Bitcoin, index, None)
+        );
+    }
+
+    #[test]
+    fn checked_key_derivation_rejects_mismatched_descriptor_type() {
+        let scheme = Bip43::Bip84;
+        let index = UnhardenedIndex::try_from(0u32).unwrap();
+        let err = scheme
+            .to_checked_key_derivation(
+                DescriptorType::Tr,
+                HardenedIndex(0).into(),
+                DerivationBlockchain::Bitcoin,
+                index,
+                None,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DerivationMismatch::DescriptorType(DescriptorType::Tr, scheme.to_string())
+        );
+    }
+
+    #[test]
+    fn checked_key_derivation_rejects_unhardened_account() {
+        let scheme = Bip43::Bip84;
+        let index = UnhardenedIndex::try_from(0u32).unwrap();
+        let unhardened_account = ChildNumber::Normal { index: 0 };
+        let err = scheme
+            .to_checked_key_derivation(
+                DescriptorType::Wpkh,
+                unhardened_account,
+                DerivationBlockchain::Bitcoin,
+                index,
+                None,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DerivationMismatch::UnhardenedAccountIndex(unhardened_account, scheme.to_string())
+        );
+    }
+
+    #[test]
+    fn curve_allows_unhardened_derivation_only_for_secp256k1() {
+        assert!(Curve::Secp256k1.allows_unhardened_derivation());
+        assert!(!Curve::Ed25519.allows_unhardened_derivation());
+    }
+
+    #[test]
+    fn enforce_curve_leaves_secp256k1_paths_unhardened() {
+        let scheme = Bip43::Bip84;
+        let path = scheme.to_key_derivation(
+            HardenedIndex(0).into(),
+            DerivationBlockchain::Bitcoin,
+            UnhardenedIndex::try_from(3u32).unwrap(),
+            None,
+        );
+        assert_eq!(scheme.enforce_curve(path.clone()), path);
+    }
+
+    #[test]
+    fn enforce_curve_hardens_ed25519_paths() {
+        let scheme = Slip10(Bip43::Bip44);
+        let unhardened = DerivationPath::from(vec![ChildNumber::Normal { index: 3 }]);
+        let hardened = scheme.enforce_curve(unhardened);
+        assert!(hardened
+            .into_iter()
+            .all(|child_number| matches!(child_number, ChildNumber::Hardened { .. })));
+    }
+}
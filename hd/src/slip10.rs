@@ -0,0 +1,222 @@
+// Descriptor wallet library extending bitcoin & miniscript functionality
+// by LNP/BP Association (https://lnp-bp.org)
+// Written in 2020-2022 by
+//     Dr. Maxim Orlovsky <orlovsky@pandoracore.com>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the Apache-2.0 License
+// along with this software.
+// If not, see <https://opensource.org/licenses/Apache-2.0>.
+
+//! SLIP-0010 key derivation for curves which, unlike secp256k1, do not
+//! support non-hardened (public) child key derivation.
+//!
+//! Currently only ed25519 is implemented, since it is the curve used by
+//! [`crate::schemata::Slip10`] wallets (e.g. Solana, Duniter).
+
+use core::convert::TryFrom;
+
+use bitcoin::hashes::{sha512, Hash, HashEngine, Hmac, HmacEngine};
+use bitcoin::util::bip32::{ChildNumber, DerivationPath};
+
+use crate::HardenedIndex;
+
+/// Length, in bytes, of a SLIP-0010 private key or chain code component.
+const PART_LEN: usize = 32;
+
+/// HMAC key used to derive the SLIP-0010 ed25519 master key, as fixed by the
+/// SLIP-0010 specification.
+const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+
+/// Errors occurring during SLIP-0010 ed25519 derivation.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Error, Display)]
+#[display(doc_comments)]
+pub enum Slip10Error {
+    /// ed25519 SLIP-0010 derivation supports only hardened child indexes;
+    /// index {0} is not hardened
+    UnhardenedChildIndex(u32),
+}
+
+/// An ed25519 extended private key derived per SLIP-0010: a 32-byte private
+/// scalar (`IL`) together with its 32-byte chain code (`IR`).
+///
+/// ed25519 has no public (neutered) derivation, so unlike
+/// `bitcoin::util::bip32::ExtendedPrivKey` there is no counterpart extended
+/// public key capable of deriving further children.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Ed25519ExtendedPrivKey {
+    /// 32-byte ed25519 private scalar.
+    pub private_key: [u8; PART_LEN],
+    /// 32-byte chain code.
+    pub chain_code: [u8; PART_LEN],
+}
+
+impl Ed25519ExtendedPrivKey {
+    /// Derives the master key from a seed, per
+    /// `I = HMAC-SHA512(key = "ed25519 seed", data = seed)`.
+    pub fn master(seed: &[u8]) -> Self { Self::from_i(&hmac_sha512(ED25519_SEED_KEY, seed)) }
+
+    /// Derives a single hardened child key, per
+    /// `I = HMAC-SHA512(key = chain_code, data = 0x00 || private_key ||
+    /// ser32(i))`, rejecting indexes which are not hardened.
+    pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self, Slip10Error> {
+        let index = HardenedIndex::try_from(child_number)
+            .map_err(|_| Slip10Error::UnhardenedChildIndex(u32::from(child_number)))?;
+
+        let mut data = Vec::with_capacity(1 + PART_LEN + 4);
+        data.push(0x00);
+        data.extend_from_slice(&self.private_key);
+        data.extend_from_slice(&u32::from(ChildNumber::from(index)).to_be_bytes());
+
+        Ok(Self::from_i(&hmac_sha512(&self.chain_code, &data)))
+    }
+
+    /// Derives the key reached by following `path` from this key, rejecting
+    /// the first unhardened segment encountered.
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Slip10Error> {
+        let mut key = self.clone();
+        for child_number in path.into_iter().copied() {
+            key = key.derive_child(child_number)?;
+        }
+        Ok(key)
+    }
+
+    fn from_i(i: &[u8; 64]) -> Self {
+        let mut private_key = [0u8; PART_LEN];
+        let mut chain_code = [0u8; PART_LEN];
+        private_key.copy_from_slice(&i[..PART_LEN]);
+        chain_code.copy_from_slice(&i[PART_LEN..]);
+        Self {
+            private_key,
+            chain_code,
+        }
+    }
+
+    /// Computes the ed25519 public key corresponding to this private scalar:
+    /// SHA-512 the 32-byte scalar, clamp it per RFC 8032, and multiply the
+    /// ed25519 basepoint by the clamped result.
+    ///
+    /// There is no public (neutered) derivation for ed25519: deriving
+    /// further children always requires the private key, never just this
+    /// public key.
+    #[cfg(feature = "ed25519")]
+    pub fn public_key(&self) -> [u8; PART_LEN] {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&self.private_key);
+        signing_key.verifying_key().to_bytes()
+    }
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut engine = HmacEngine::<sha512::Hash>::new(key);
+    engine.input(data);
+    Hmac::<sha512::Hash>::from_engine(engine).into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // SLIP-0010 ed25519 test vector 1, seed 000102030405060708090a0b0c0d0e0f.
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn master_key_matches_test_vector() {
+        let seed = from_hex(SEED);
+        let master = Ed25519ExtendedPrivKey::master(&seed);
+        assert_eq!(
+            to_hex(&master.private_key),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            to_hex(&master.chain_code),
+            "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb"
+        );
+    }
+
+    #[test]
+    fn child_key_matches_test_vector() {
+        let seed = from_hex(SEED);
+        let master = Ed25519ExtendedPrivKey::master(&seed);
+
+        let child0h = master
+            .derive_child(ChildNumber::Hardened { index: 0 })
+            .expect("index 0 is hardened");
+        assert_eq!(
+            to_hex(&child0h.private_key),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+        assert_eq!(
+            to_hex(&child0h.chain_code),
+            "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69"
+        );
+
+        let path = DerivationPath::from(vec![
+            ChildNumber::Hardened { index: 0 },
+            ChildNumber::Hardened { index: 1 },
+        ]);
+        let child0h1h = master.derive_path(&path).expect("path is fully hardened");
+        assert_eq!(
+            to_hex(&child0h1h.private_key),
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+        );
+        assert_eq!(
+            to_hex(&child0h1h.chain_code),
+            "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14"
+        );
+    }
+
+    #[test]
+    fn derive_child_rejects_unhardened_index() {
+        let seed = from_hex(SEED);
+        let master = Ed25519ExtendedPrivKey::master(&seed);
+        let err = master
+            .derive_child(ChildNumber::Normal { index: 0 })
+            .unwrap_err();
+        assert_eq!(err, Slip10Error::UnhardenedChildIndex(0));
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn public_key_matches_test_vector() {
+        let seed = from_hex(SEED);
+        let master = Ed25519ExtendedPrivKey::master(&seed);
+        assert_eq!(
+            to_hex(&master.public_key()),
+            "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"
+        );
+
+        let child0h = master
+            .derive_child(ChildNumber::Hardened { index: 0 })
+            .expect("index 0 is hardened");
+        assert_eq!(
+            to_hex(&child0h.public_key()),
+            "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c"
+        );
+
+        let path = DerivationPath::from(vec![
+            ChildNumber::Hardened { index: 0 },
+            ChildNumber::Hardened { index: 1 },
+        ]);
+        let child0h1h = master.derive_path(&path).expect("path is fully hardened");
+        assert_eq!(
+            to_hex(&child0h1h.public_key()),
+            "1932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187"
+        );
+    }
+}